@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+//! Criterion benchmarks for cursorsort, covering the canonical input
+//! distributions used to evaluate Rust's own sorts: fully ascending, fully
+//! descending, uniformly random, mostly-ascending, mostly-descending,
+//! all-equal, and "big element" arrays that expose swap cost. Data is
+//! generated from a fixed-seed deterministic xorshift RNG so runs are
+//! reproducible across machines.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use cursorsort::{bench_heapsort_by, cursorsort, cursorsort_by_with_threshold, cursorsort_total};
+
+const SIZES: [usize; 3] = [10, 1_000, 100_000];
+const THRESHOLDS: [usize; 5] = [0, 8, 16, 32, 64];
+const SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+// xorshift64 is a small, dependency-free, deterministic PRNG: good enough
+// for generating reproducible benchmark input, not for anything else.
+fn xorshift64(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+fn ascending(len: usize) -> Vec<u64> {
+    (0..len as u64).collect()
+}
+
+fn descending(len: usize) -> Vec<u64> {
+    (0..len as u64).rev().collect()
+}
+
+fn random(len: usize, seed: u64) -> Vec<u64> {
+    let mut state = seed;
+    (0..len).map(|_| xorshift64(&mut state)).collect()
+}
+
+// mostly_sorted takes an already-ordered run and disturbs it with a handful
+// of random swaps, the "sorted with a few random swaps" pattern used to
+// stress the sorted/reverse-sorted prescan and pivot selection together.
+fn mostly_sorted(mut v: Vec<u64>, seed: u64) -> Vec<u64> {
+    let len = v.len();
+    if len < 2 {
+        return v;
+    }
+    let mut state = seed;
+    let swaps = (len / 100).max(1);
+    for _ in 0..swaps {
+        let i = (xorshift64(&mut state) as usize) % len;
+        let j = (xorshift64(&mut state) as usize) % len;
+        v.swap(i, j);
+    }
+    v
+}
+
+fn all_equal(len: usize) -> Vec<u64> {
+    vec![42; len]
+}
+
+fn big_elements(len: usize, seed: u64) -> Vec<[u64; 16]> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| core::array::from_fn(|_| xorshift64(&mut state)))
+        .collect()
+}
+
+// random_with_nans is `random` scaled to f64, with roughly 1 in 20 values
+// replaced by NaN, the input cursorsort_total is built for.
+fn random_with_nans(len: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            let bits = xorshift64(&mut state);
+            if bits.is_multiple_of(20) {
+                f64::NAN
+            } else {
+                (bits % 1_000_000) as f64
+            }
+        })
+        .collect()
+}
+
+fn bench_input_patterns(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cursorsort");
+    for &len in &SIZES {
+        let patterns: [(&str, Vec<u64>); 6] = [
+            ("ascending", ascending(len)),
+            ("descending", descending(len)),
+            ("random", random(len, SEED)),
+            ("mostly_ascending", mostly_sorted(ascending(len), SEED)),
+            ("mostly_descending", mostly_sorted(descending(len), SEED)),
+            ("all_equal", all_equal(len)),
+        ];
+        for (name, data) in patterns {
+            group.bench_with_input(BenchmarkId::new(name, len), &data, |b, data| {
+                b.iter_batched(
+                    || data.clone(),
+                    |mut v| cursorsort(black_box(&mut v), false),
+                    BatchSize::SmallInput,
+                );
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_big_elements(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cursorsort_big_elements");
+    for &len in &SIZES {
+        let data = big_elements(len, SEED);
+        group.bench_with_input(BenchmarkId::new("u64x16", len), &data, |b, data| {
+            b.iter_batched(
+                || data.clone(),
+                |mut v| cursorsort(black_box(&mut v), false),
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+// bench_insertion_threshold sweeps the insertion-sort crossover on random
+// input, so the threshold tunable added alongside the cursor partitioning
+// can actually be measured rather than only exercised at its default.
+fn bench_insertion_threshold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cursorsort_insertion_threshold");
+    for &len in &SIZES {
+        let data = random(len, SEED);
+        for &threshold in &THRESHOLDS {
+            group.bench_with_input(
+                BenchmarkId::new(format!("threshold_{threshold}"), len),
+                &data,
+                |b, data| {
+                    b.iter_batched(
+                        || data.clone(),
+                        |mut v| {
+                            cursorsort_by_with_threshold(
+                                black_box(&mut v),
+                                |a, b| a.cmp(b),
+                                threshold,
+                            )
+                        },
+                        BatchSize::SmallInput,
+                    );
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+// bench_heapsort_fallback measures the introsort heapsort fallback in
+// isolation (bypassing cursor partitioning entirely via
+// `bench_heapsort_by`, so pivot selection is not involved either), so the
+// introsort fallback can be measured independently of how rarely real
+// input actually reaches it.
+fn bench_heapsort_fallback(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cursorsort_heapsort_fallback");
+    for &len in &SIZES {
+        let data = random(len, SEED);
+        group.bench_with_input(BenchmarkId::new("heapsort", len), &data, |b, data| {
+            b.iter_batched(
+                || data.clone(),
+                |mut v| bench_heapsort_by(black_box(&mut v), |a, b| a.cmp(b)),
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+// bench_cursorsort_total measures cursorsort_total over f64 input sprinkled
+// with NaNs, the scenario it exists for.
+fn bench_cursorsort_total(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cursorsort_total");
+    for &len in &SIZES {
+        let data = random_with_nans(len, SEED);
+        group.bench_with_input(BenchmarkId::new("f64_with_nans", len), &data, |b, data| {
+            b.iter_batched(
+                || data.clone(),
+                |mut v| cursorsort_total(black_box(&mut v)),
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_input_patterns,
+    bench_big_elements,
+    bench_insertion_threshold,
+    bench_heapsort_fallback,
+    bench_cursorsort_total,
+);
+criterion_main!(benches);
@@ -12,12 +12,112 @@ use core::cmp::Ordering;
 /// This function works on arrays, slices, and vectors of any type satisfying
 /// the trait requirement. If a type can be turned into a vector like a String
 /// it will also be able to sort that.
+///
+/// Internally this is implemented in terms of [`cursorsort_by`], passing a
+/// comparator that flips the result when `descending` is true.
 pub fn cursorsort<T: PartialOrd>(arr: &mut [T], descending: bool) {
+    cursorsort_by(arr, |a, b| {
+        let ord = PartialOrd::partial_cmp(a, b).unwrap_or(Ordering::Equal);
+        if descending {
+            ord.reverse()
+        } else {
+            ord
+        }
+    });
+}
+
+/// The default length below which [`cursorsort_by`] diverts to insertion
+/// sort instead of recursing further. Short subslices partition poorly with
+/// the cursor scheme, so below this size a simple insertion sort wins; see
+/// [`cursorsort_by_with_threshold`] to tune this for a specific workload.
+pub const DEFAULT_INSERTION_THRESHOLD: usize = 20;
+
+/// cursorsort_by sorts in place a slice of any type using a user-supplied
+/// comparator, mirroring the standard library's `slice::sort_by`. It uses the
+/// same cursor based partitioning and pivot selection as [`cursorsort`], so
+/// callers can sort by a derived key, reverse only part of a struct, or sort
+/// types that have no natural ordering.
+///
+/// To guard against the quadratic worst case and stack overflow that plain
+/// cursor partitioning can hit on already-sorted, reverse-sorted, or
+/// many-equal input, recursion is capped by a depth limit derived from the
+/// slice length. Once that limit is exhausted the current subslice is
+/// finished off with an in-place heapsort instead of partitioning further,
+/// bounding worst-case time at O(n log n). Subslices shorter than
+/// [`DEFAULT_INSERTION_THRESHOLD`] are sorted with insertion sort instead;
+/// use [`cursorsort_by_with_threshold`] to tune that crossover.
+pub fn cursorsort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: F) {
+    cursorsort_by_with_threshold(arr, compare, DEFAULT_INSERTION_THRESHOLD);
+}
+
+/// cursorsort_by_with_threshold behaves exactly like [`cursorsort_by`], but
+/// lets the caller choose the subslice length below which sorting diverts to
+/// insertion sort, instead of using [`DEFAULT_INSERTION_THRESHOLD`]. This
+/// exists so the insertion-sort crossover can be measured and tuned for a
+/// particular element type and workload.
+pub fn cursorsort_by_with_threshold<T, F: FnMut(&T, &T) -> Ordering>(
+    arr: &mut [T],
+    mut compare: F,
+    insertion_threshold: usize,
+) {
+    let depth_limit = depth_limit_for(arr.len());
+    cursorsort_by_impl(arr, &mut compare, depth_limit, insertion_threshold);
+}
+
+// depth_limit_for computes the introsort-style recursion budget for a slice
+// of the given length: roughly 2 * floor(log2(len)). Partitioning is allowed
+// to recurse that many levels deep before falling back to heapsort.
+fn depth_limit_for(len: usize) -> u32 {
+    if len <= 1 {
+        0
+    } else {
+        2 * len.ilog2()
+    }
+}
+
+// cursorsort_by_impl is the recursive engine behind cursorsort_by. It takes
+// the comparator by mutable reference so that the generic instantiation does
+// not grow a new `&mut` layer on every recursive call.
+fn cursorsort_by_impl<T, F: FnMut(&T, &T) -> Ordering>(
+    arr: &mut [T],
+    compare: &mut F,
+    depth_limit: u32,
+    insertion_threshold: usize,
+) {
     // If the array is empty or of length 1, return it as is.
     if arr.len() <= 1 {
         return;
     }
 
+    // Short subslices partition very inefficiently with the cursor scheme;
+    // insertion sort is faster below the configured threshold.
+    if arr.len() < insertion_threshold {
+        insertion_sort_by(arr, compare);
+        return;
+    }
+
+    // The depth budget is exhausted: stop partitioning and fall back to
+    // heapsort, which is guaranteed O(n log n) regardless of input shape.
+    if depth_limit == 0 {
+        heapsort_by(arr, compare);
+        return;
+    }
+
+    // Cheaply detect an already-sorted, or exactly reverse-sorted, run and
+    // handle it without partitioning at all.
+    if prescan_sorted_or_reverse(arr, compare) {
+        return;
+    }
+
+    // Pick a good pivot up front instead of letting one emerge wherever the
+    // cursors happen to meet, and swap it to the end: the cursor dance below
+    // holds the element at the last index fixed and partitions around it.
+    // This defeats the sorted/organ-pipe inputs that make a meeting-point
+    // pivot degenerate.
+    let pivot = choose_pivot_index(arr, compare);
+    let last = arr.len() - 1;
+    arr.swap(last, pivot);
+
     // Initialise the cursors.
     let mut cur1 = 0;
     let mut cur2 = arr.len() - 1;
@@ -28,37 +128,19 @@ pub fn cursorsort<T: PartialOrd>(arr: &mut [T], descending: bool) {
         // Compare the cursors and the indexed elements and swap them if they
         // are not in the correct place.
         let mut swap = false;
-        if !descending {
-            // If the descending argument is false, sort ascending.
-            match PartialOrd::partial_cmp(&arr[cur1], &arr[cur2]) {
-                Some(Ordering::Greater) => {
-                    if cur1 < cur2 {
-                        swap = true
-                    }
+        match compare(&arr[cur1], &arr[cur2]) {
+            Ordering::Greater => {
+                if cur1 < cur2 {
+                    swap = true
                 }
-                Some(Ordering::Less) => {
-                    if cur1 > cur2 {
-                        swap = true
-                    }
+            }
+            Ordering::Less => {
+                if cur1 > cur2 {
+                    swap = true
                 }
-                _ => {}
-            };
-        } else {
-            // If the descending argument is true, sort descending.
-            match PartialOrd::partial_cmp(&arr[cur1], &arr[cur2]) {
-                Some(Ordering::Greater) => {
-                    if cur1 > cur2 {
-                        swap = true
-                    }
-                }
-                Some(Ordering::Less) => {
-                    if cur1 < cur2 {
-                        swap = true
-                    }
-                }
-                _ => {}
-            };
-        }
+            }
+            Ordering::Equal => {}
+        };
 
         // Swap the elements at the cursors and the cursors themselves.
         if swap {
@@ -74,10 +156,234 @@ pub fn cursorsort<T: PartialOrd>(arr: &mut [T], descending: bool) {
         }
     }
 
-    // Recursively call cursorsort on the subarrays using the correctly placed
-    // pivot element freom the while loop.
-    cursorsort(&mut arr[..cur1], descending);
-    cursorsort(&mut arr[cur1 + 1..], descending);
+    // Recursively call cursorsort_by_impl on the subarrays using the
+    // correctly placed pivot element freom the while loop.
+    cursorsort_by_impl(
+        &mut arr[..cur1],
+        compare,
+        depth_limit - 1,
+        insertion_threshold,
+    );
+    cursorsort_by_impl(
+        &mut arr[cur1 + 1..],
+        compare,
+        depth_limit - 1,
+        insertion_threshold,
+    );
+}
+
+// prescan_sorted_or_reverse detects an already-sorted, or exactly
+// reverse-sorted, run with respect to `compare` and handles it without
+// partitioning at all: an already-sorted run is left untouched, and an
+// exactly reverse-sorted one is reversed in place. Returns true in either
+// case, meaning the caller has nothing further to do.
+fn prescan_sorted_or_reverse<T, F: FnMut(&T, &T) -> Ordering>(
+    arr: &mut [T],
+    compare: &mut F,
+) -> bool {
+    if is_sorted_by(arr, |a, b| compare(a, b)) {
+        return true;
+    }
+    if is_sorted_by(arr, |a, b| compare(b, a)) {
+        arr.reverse();
+        return true;
+    }
+    false
+}
+
+/// is_ascending returns true if `arr` is sorted in non-decreasing order, per
+/// `PartialOrd`. An empty slice or a slice of one element is always
+/// considered sorted. Incomparable adjacent elements (`partial_cmp` returning
+/// `None`) do not break the run.
+pub fn is_ascending<T: PartialOrd>(arr: &[T]) -> bool {
+    is_sorted_by(arr, |a, b| {
+        PartialOrd::partial_cmp(a, b).unwrap_or(Ordering::Equal)
+    })
+}
+
+/// is_descending returns true if `arr` is sorted in non-increasing order, per
+/// `PartialOrd`. An empty slice or a slice of one element is always
+/// considered sorted. Incomparable adjacent elements (`partial_cmp`
+/// returning `None`) do not break the run.
+pub fn is_descending<T: PartialOrd>(arr: &[T]) -> bool {
+    is_sorted_by(arr, |a, b| {
+        PartialOrd::partial_cmp(b, a).unwrap_or(Ordering::Equal)
+    })
+}
+
+/// is_sorted_by returns true if `arr` is sorted in non-decreasing order
+/// according to `compare`, mirroring the standard library's
+/// `slice::is_sorted_by`. An empty slice or a slice of one element is always
+/// considered sorted. This is the comparator counterpart to [`is_ascending`]
+/// and [`is_descending`], matching the [`cursorsort_by`] API.
+pub fn is_sorted_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &[T], mut compare: F) -> bool {
+    arr.windows(2)
+        .all(|w| compare(&w[0], &w[1]) != Ordering::Greater)
+}
+
+// The ninther pivot selection kicks in once a subslice is large enough that
+// a single median-of-three sample is unlikely to be representative.
+const NINTHER_THRESHOLD: usize = 128;
+
+// choose_pivot_index picks a pivot index for `arr` that resists the common
+// adversarial cases (sorted, reverse-sorted, organ-pipe input) that make a
+// meeting-point pivot degenerate: a plain median-of-three for medium
+// slices, and a "ninther" (median of three medians) for large ones.
+fn choose_pivot_index<T, F: FnMut(&T, &T) -> Ordering>(arr: &[T], compare: &mut F) -> usize {
+    let len = arr.len();
+    if len > NINTHER_THRESHOLD {
+        ninther_index(arr, compare)
+    } else {
+        median_of_three_index(arr, 0, len / 2, len - 1, compare)
+    }
+}
+
+// ninther_index samples three evenly spaced triplets across `arr`, reduces
+// each to its median, and returns the index of the median of those medians.
+fn ninther_index<T, F: FnMut(&T, &T) -> Ordering>(arr: &[T], compare: &mut F) -> usize {
+    let len = arr.len();
+    let third = len / 3;
+
+    let m1 = median_of_three_index(arr, 0, third / 2, third, compare);
+    let m2 = median_of_three_index(arr, third, third + third / 2, 2 * third, compare);
+    let m3 = median_of_three_index(arr, 2 * third, 2 * third + third / 2, len - 1, compare);
+
+    median_of_three_index(arr, m1, m2, m3, compare)
+}
+
+// median_of_three_index returns whichever of `a`, `b`, `c` indexes the
+// median value of the three, without mutating `arr`.
+fn median_of_three_index<T, F: FnMut(&T, &T) -> Ordering>(
+    arr: &[T],
+    a: usize,
+    b: usize,
+    c: usize,
+    compare: &mut F,
+) -> usize {
+    if compare(&arr[a], &arr[b]) == Ordering::Less {
+        if compare(&arr[b], &arr[c]) == Ordering::Less {
+            b
+        } else if compare(&arr[a], &arr[c]) == Ordering::Less {
+            c
+        } else {
+            a
+        }
+    } else if compare(&arr[a], &arr[c]) == Ordering::Less {
+        a
+    } else if compare(&arr[b], &arr[c]) == Ordering::Less {
+        c
+    } else {
+        b
+    }
+}
+
+// insertion_sort_by sorts `arr` in place using a stable insertion sort,
+// ordering elements so that `compare` never reports a later element as
+// `Less` than an earlier one. It is used for subslices shorter than the
+// insertion threshold, where the cursor partitioning scheme pays a fixed
+// overhead that insertion sort avoids.
+fn insertion_sort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: &mut F) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && compare(&arr[j - 1], &arr[j]) == Ordering::Greater {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// bench_heapsort_by runs the introsort's heapsort fallback directly,
+/// bypassing cursor partitioning entirely. It exists so the benchmark suite
+/// can measure the fallback in isolation, and is only compiled in behind the
+/// `bench-internal` feature; it is not part of the stable public API.
+#[cfg(feature = "bench-internal")]
+#[doc(hidden)]
+pub fn bench_heapsort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], mut compare: F) {
+    heapsort_by(arr, &mut compare);
+}
+
+// heapsort_by sorts `arr` in place using a sift-down based binary heap,
+// ordering elements so that `compare` never reports a later element as
+// `Less` than an earlier one. This is the introsort fallback used once the
+// cursor partitioning recursion budget is exhausted; it runs in O(n log n)
+// time regardless of the input's shape.
+fn heapsort_by<T, F: FnMut(&T, &T) -> Ordering>(arr: &mut [T], compare: &mut F) {
+    let len = arr.len();
+
+    // Build a max-heap (with respect to `compare`) in place.
+    for start in (0..len / 2).rev() {
+        sift_down(arr, start, len, compare);
+    }
+
+    // Repeatedly move the heap's root, the largest remaining element, to the
+    // end of the unsorted region, then restore the heap property.
+    for end in (1..len).rev() {
+        arr.swap(0, end);
+        sift_down(arr, 0, end, compare);
+    }
+}
+
+// sift_down restores the max-heap property for the subtree rooted at `root`
+// within `arr[..len]`, assuming both children subtrees are already valid
+// heaps.
+fn sift_down<T, F: FnMut(&T, &T) -> Ordering>(
+    arr: &mut [T],
+    mut root: usize,
+    len: usize,
+    compare: &mut F,
+) {
+    loop {
+        let left = 2 * root + 1;
+        let right = 2 * root + 2;
+        let mut largest = root;
+
+        if left < len && compare(&arr[left], &arr[largest]) == Ordering::Greater {
+            largest = left;
+        }
+        if right < len && compare(&arr[right], &arr[largest]) == Ordering::Greater {
+            largest = right;
+        }
+
+        if largest == root {
+            break;
+        }
+
+        arr.swap(root, largest);
+        root = largest;
+    }
+}
+
+/// cursorsort_by_key sorts in place a slice by the key extracted from each
+/// element with `f`, mirroring the standard library's `slice::sort_by_key`.
+/// It is a convenience wrapper around [`cursorsort_by`].
+pub fn cursorsort_by_key<T, K: Ord, F: FnMut(&T) -> K>(arr: &mut [T], mut f: F) {
+    cursorsort_by(arr, |a, b| f(a).cmp(&f(b)));
+}
+
+/// cursorsort_total sorts in place a slice of any `PartialOrd` type,
+/// providing a well-defined total order even when `partial_cmp` returns
+/// `None` for some pairs (NaN floats, or other partially-ordered types).
+///
+/// [`cursorsort`] leaves such pairs untouched, which can leave the slice
+/// unsorted around them. cursorsort_total instead routes every element that
+/// is incomparable with itself (`partial_cmp(&x, &x).is_none()`, the
+/// signature of NaN-like values) to the end of the slice, and sorts the
+/// remaining, fully comparable, elements in ascending order. The relative
+/// order of the routed-to-the-end elements is otherwise unspecified.
+pub fn cursorsort_total<T: PartialOrd>(arr: &mut [T]) {
+    // Partition in place: comparable elements to the front, incomparable
+    // ones (e.g. NaN) to the back.
+    let mut boundary = 0;
+    for i in 0..arr.len() {
+        if PartialOrd::partial_cmp(&arr[i], &arr[i]).is_some() {
+            arr.swap(boundary, i);
+            boundary += 1;
+        }
+    }
+
+    cursorsort_by(&mut arr[..boundary], |a, b| {
+        PartialOrd::partial_cmp(a, b).unwrap_or(Ordering::Equal)
+    });
 }
 
 // Unit tests
@@ -538,4 +844,272 @@ mod tests {
         let sorted_string = String::from_utf8(bytes).unwrap();
         assert_eq!(sorted_string, "wroolllhed ");
     }
+
+    #[test]
+    fn test_cursorsort_by_ascending() {
+        let mut arr = [54, 24, 53, 6, 2, 2, 5, 6, 7, 2];
+        cursorsort_by(&mut arr, |a, b| a.cmp(b));
+        assert_eq!(arr, [2, 2, 2, 5, 6, 6, 7, 24, 53, 54]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_descending() {
+        let mut arr = [54, 24, 53, 6, 2, 2, 5, 6, 7, 2];
+        cursorsort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, [54, 53, 24, 7, 6, 6, 5, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_tuple_second_field() {
+        let mut arr = [(1, 3), (2, 1), (3, 2)];
+        cursorsort_by(&mut arr, |a, b| a.1.cmp(&b.1));
+        assert_eq!(arr, [(2, 1), (3, 2), (1, 3)]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_paths_by_filename() {
+        let mut paths = ["/usr/z.txt", "/a/m.txt", "/c/a.txt"];
+        cursorsort_by(&mut paths, |a, b| {
+            let a_name = a.rsplit('/').next().unwrap();
+            let b_name = b.rsplit('/').next().unwrap();
+            a_name.cmp(b_name)
+        });
+        assert_eq!(paths, ["/c/a.txt", "/a/m.txt", "/usr/z.txt"]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_key_ascending() {
+        let mut arr = [(-5i32, "e"), (3i32, "b"), (-1i32, "c")];
+        cursorsort_by_key(&mut arr, |pair| pair.0.abs());
+        assert_eq!(arr, [(-1, "c"), (3, "b"), (-5, "e")]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_key_strings_by_length() {
+        let mut arr = ["ccc", "a", "bb"];
+        cursorsort_by_key(&mut arr, |s| s.len());
+        assert_eq!(arr, ["a", "bb", "ccc"]);
+    }
+
+    #[test]
+    fn test_large_already_sorted_does_not_overflow_stack() {
+        let mut arr: Vec<i32> = (0..20_000).collect();
+        cursorsort(&mut arr, false);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_large_reverse_sorted_does_not_overflow_stack() {
+        let mut arr: Vec<i32> = (0..20_000).rev().collect();
+        cursorsort(&mut arr, false);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_large_all_equal_does_not_overflow_stack() {
+        let mut arr: Vec<i32> = vec![7; 20_000];
+        cursorsort(&mut arr, false);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_heapsort_by_sorts_ascending() {
+        let mut arr = [5, 3, 8, 1, 9, 2, 7, 4, 6];
+        heapsort_by(&mut arr, &mut |a: &i32, b: &i32| a.cmp(b));
+        assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_heapsort_by_sorts_descending() {
+        let mut arr = [5, 3, 8, 1, 9, 2, 7, 4, 6];
+        heapsort_by(&mut arr, &mut |a: &i32, b: &i32| b.cmp(a));
+        assert_eq!(arr, [9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_heapsort_by_handles_empty_and_single() {
+        let mut empty: [i32; 0] = [];
+        heapsort_by(&mut empty, &mut |a: &i32, b: &i32| a.cmp(b));
+        assert_eq!(empty, []);
+
+        let mut single = [1];
+        heapsort_by(&mut single, &mut |a: &i32, b: &i32| a.cmp(b));
+        assert_eq!(single, [1]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_impl_falls_back_to_heapsort_when_depth_exhausted() {
+        // Drive cursorsort_by_impl directly with a depth limit of zero so
+        // the fallback heapsort handles the sort rather than partitioning.
+        let mut arr: Vec<i32> = (0..60).chain((0..60).rev()).collect();
+        let mut compare = |a: &i32, b: &i32| a.cmp(b);
+        cursorsort_by_impl(&mut arr, &mut compare, 0, DEFAULT_INSERTION_THRESHOLD);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_insertion_sort_threshold_small_arr() {
+        let mut arr = [5, 3, 4, 1, 2];
+        cursorsort_by(&mut arr, |a, b| a.cmp(b));
+        assert_eq!(arr, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_with_threshold_forces_insertion_sort() {
+        // A threshold larger than the slice length means every call takes
+        // the insertion-sort path regardless of the cursor partitioning.
+        let mut arr = [9, 1, 8, 2, 7, 3, 6, 4, 5];
+        let threshold = arr.len() + 1;
+        cursorsort_by_with_threshold(&mut arr, |a, b| a.cmp(b), threshold);
+        assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_cursorsort_by_with_threshold_tiny_threshold() {
+        // A threshold of 0 disables insertion sort entirely, exercising the
+        // plain cursor partitioning path for every subslice.
+        let mut arr = [9, 1, 8, 2, 7, 3, 6, 4, 5];
+        cursorsort_by_with_threshold(&mut arr, |a, b| a.cmp(b), 0);
+        assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_medium_organ_pipe_input() {
+        // Rises then falls, a classic case that degenerates a meeting-point
+        // pivot but is handled fine by median-of-three selection.
+        let mut arr: Vec<i32> = (0..60).chain((0..60).rev()).collect();
+        cursorsort_by_with_threshold(&mut arr, |a, b| a.cmp(b), 0);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_large_sorted_input_short_circuits_in_prescan() {
+        // Larger than the ninther threshold, already sorted: the prescan
+        // should short-circuit before pivot selection even runs.
+        let mut arr: Vec<i32> = (0..500).collect();
+        cursorsort_by_with_threshold(&mut arr, |a, b| a.cmp(b), 0);
+        assert_eq!(arr, (0..500).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_large_reverse_sorted_input_reversed_in_prescan() {
+        let mut arr: Vec<i32> = (0..500).rev().collect();
+        cursorsort_by_with_threshold(&mut arr, |a, b| a.cmp(b), 0);
+        assert_eq!(arr, (0..500).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_large_random_input_with_duplicates() {
+        // Deterministic pseudo-random input with plenty of duplicate keys,
+        // exercised with the ninther pivot path and no insertion-sort escape
+        // hatch.
+        let mut state: u64 = 0x2545F4914F6CDD1D;
+        let mut arr: Vec<u32> = (0..500)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state % 50) as u32
+            })
+            .collect();
+        cursorsort_by_with_threshold(&mut arr, |a, b| a.cmp(b), 0);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_is_ascending_empty_and_single() {
+        let empty: [i32; 0] = [];
+        assert!(is_ascending(&empty));
+        assert!(is_ascending(&[1]));
+    }
+
+    #[test]
+    fn test_is_ascending_true() {
+        assert!(is_ascending(&[1, 2, 2, 3, 10]));
+    }
+
+    #[test]
+    fn test_is_ascending_false() {
+        assert!(!is_ascending(&[1, 3, 2]));
+    }
+
+    #[test]
+    fn test_is_descending_empty_and_single() {
+        let empty: [i32; 0] = [];
+        assert!(is_descending(&empty));
+        assert!(is_descending(&[1]));
+    }
+
+    #[test]
+    fn test_is_descending_true() {
+        assert!(is_descending(&[10, 3, 2, 2, 1]));
+    }
+
+    #[test]
+    fn test_is_descending_false() {
+        assert!(!is_descending(&[3, 1, 2]));
+    }
+
+    #[test]
+    fn test_is_sorted_by_key() {
+        let arr = ["a", "bb", "ccc"];
+        assert!(is_sorted_by(&arr, |a, b| a.len().cmp(&b.len())));
+        assert!(!is_sorted_by(&arr, |a, b| b.len().cmp(&a.len())));
+    }
+
+    #[test]
+    fn test_cursorsort_asserts_is_ascending_postcondition() {
+        let mut arr = [54, 24, 53, 6, 2, 2, 5, 6, 7, 2];
+        cursorsort(&mut arr, false);
+        assert!(is_ascending(&arr));
+    }
+
+    #[test]
+    fn test_cursorsort_asserts_is_descending_postcondition() {
+        let mut arr = [54, 24, 53, 6, 2, 2, 5, 6, 7, 2];
+        cursorsort(&mut arr, true);
+        assert!(is_descending(&arr));
+    }
+
+    #[test]
+    fn test_cursorsort_total_no_nan() {
+        let mut arr = [5.0, 3.0, 1.0, 4.0, 2.0];
+        cursorsort_total(&mut arr);
+        assert_eq!(arr, [1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_cursorsort_total_single_nan_grouped_at_end() {
+        let mut arr = [3.0, f64::NAN, 1.0, 2.0];
+        cursorsort_total(&mut arr);
+        assert_eq!(&arr[..3], [1.0, 2.0, 3.0]);
+        assert!(arr[3].is_nan());
+    }
+
+    #[test]
+    fn test_cursorsort_total_multiple_nans_grouped_at_end() {
+        let mut arr = [f64::NAN, 5.0, f64::NAN, 1.0, 3.0, f64::NAN, 2.0];
+        cursorsort_total(&mut arr);
+        let (comparable, incomparable) = arr.split_at(4);
+        assert_eq!(comparable, [1.0, 2.0, 3.0, 5.0]);
+        assert!(incomparable.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_cursorsort_total_all_nan() {
+        let mut arr = [f64::NAN, f64::NAN, f64::NAN];
+        cursorsort_total(&mut arr);
+        assert!(arr.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_cursorsort_total_empty_and_single() {
+        let mut empty: [f64; 0] = [];
+        cursorsort_total(&mut empty);
+        assert_eq!(empty, []);
+
+        let mut single = [1.0];
+        cursorsort_total(&mut single);
+        assert_eq!(single, [1.0]);
+    }
 }